@@ -39,16 +39,17 @@
 //! ```
 
 use std::{
+    ffi::CString,
+    os::raw::{c_char, c_void},
     panic::{catch_unwind, AssertUnwindSafe},
     ptr,
-    sync::{Arc, Mutex, RwLock},
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc, Mutex, RwLock,
+    },
 };
 
-use emissary_core::{
-    events::EventSubscriber,
-    router::RouterBuilder,
-    Config, SamConfig,
-};
+use emissary_core::{events::EventSubscriber, router::RouterBuilder, Config, SamConfig};
 use emissary_util::runtime::tokio::Runtime;
 use tokio::sync::mpsc;
 
@@ -66,6 +67,12 @@ enum RouterState {
     Running {
         _events: EventSubscriber,
         shutdown_tx: mpsc::Sender<()>,
+        /// Handle to the spawned router task, awaited with a bounded timeout by
+        /// `emissary_stop_graceful` and `emissary_reload`.
+        join_handle: tokio::task::JoinHandle<()>,
+        /// Instant the router entered the `Running` state, used to report
+        /// uptime through `emissary_get_stats`.
+        started_at: std::time::Instant,
         _runtime: Arc<tokio::runtime::Runtime>,
     },
     /// Router is in the process of shutting down
@@ -88,22 +95,17 @@ pub struct EmissaryRouter {
 impl EmissaryRouter {
     /// Create new router instance with default configuration
     fn new() -> Self {
-        // Create default configuration with SAMv3 enabled
-        let mut config = Config::default();
-        
-        // Enable SAMv3 with default ports (0 = random port assignment)
-        config.samv3_config = Some(SamConfig {
-            tcp_port: 0, // Will be assigned by OS
-            udp_port: 0, // Will be assigned by OS
-            host: "127.0.0.1".to_string(),
-        });
-        
-        // Disable transit tunnels for minimal resource usage
-        config.transit = None;
-        
-        // Enable insecure tunnels for faster startup in development
-        config.insecure_tunnels = true;
+        Self::from_config(default_config())
+    }
 
+    /// Create a router instance from a caller-supplied [`Config`].
+    ///
+    /// Used by `emissary_init_with_config` so embedders can pin the SAM ports
+    /// instead of taking the OS-assigned ones of the development defaults
+    /// applied by [`Self::new`]. `emissary_config_t` has setters for the SAM
+    /// ports only; the data directory, transit and all other settings keep
+    /// those defaults.
+    fn from_config(config: Config) -> Self {
         Self {
             state: Mutex::new(RouterState::Stopped),
             config: RwLock::new(config),
@@ -113,6 +115,133 @@ impl EmissaryRouter {
     }
 }
 
+/// Build the minimal development `Config` used when no configuration is
+/// supplied: SAMv3 on random ports, transit disabled, insecure tunnels on.
+fn default_config() -> Config {
+    let mut config = Config::default();
+
+    // Enable SAMv3 with default ports (0 = random port assignment)
+    config.samv3_config = Some(SamConfig {
+        tcp_port: 0, // Will be assigned by OS
+        udp_port: 0, // Will be assigned by OS
+        host: "127.0.0.1".to_string(),
+    });
+
+    // Disable transit tunnels for minimal resource usage
+    config.transit = None;
+
+    // Enable insecure tunnels for faster startup in development
+    config.insecure_tunnels = true;
+
+    config
+}
+
+// ============================================================================
+// Configuration Builder (emissary_config_t)
+// ============================================================================
+
+/// Opaque configuration handle used to customize a router before it is started.
+///
+/// Create one with `emissary_config_new`, pin the SAM ports through the
+/// setters, hand it to `emissary_init_with_config`, and release it with
+/// `emissary_config_free`. The configuration is copied into the router at init
+/// time, so the handle may be freed immediately afterwards.
+pub struct EmissaryConfig {
+    config: Config,
+}
+
+impl EmissaryConfig {
+    /// Start from the same development defaults as `emissary_init`.
+    fn new() -> Self {
+        Self {
+            config: default_config(),
+        }
+    }
+}
+
+/// Create a new configuration handle seeded with the default settings.
+#[no_mangle]
+pub extern "C" fn emissary_config_new() -> *mut EmissaryConfig {
+    match catch_unwind(|| Box::into_raw(Box::new(EmissaryConfig::new()))) {
+        Ok(config_ptr) => config_ptr,
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Free a configuration handle created by `emissary_config_new`.
+#[no_mangle]
+pub extern "C" fn emissary_config_free(config_ptr: *mut EmissaryConfig) {
+    if config_ptr.is_null() {
+        return;
+    }
+
+    // Safety: This pointer came from Box::into_raw in one of the config
+    // constructors and is only freed once.
+    let _ = catch_unwind(|| unsafe {
+        let _ = Box::from_raw(config_ptr);
+    });
+}
+
+/// Pin the SAMv3 TCP port the router should bind (0 keeps OS assignment).
+#[no_mangle]
+pub extern "C" fn emissary_config_set_sam_tcp_port(
+    config_ptr: *mut EmissaryConfig,
+    port: u16,
+) -> i32 {
+    if config_ptr.is_null() {
+        return EMISSARY_ERROR_INVALID_PARAM;
+    }
+
+    // Safety: We've checked for null pointer
+    let config = unsafe { &mut *config_ptr };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        config
+            .config
+            .samv3_config
+            .get_or_insert_with(|| SamConfig {
+                tcp_port: 0,
+                udp_port: 0,
+                host: "127.0.0.1".to_string(),
+            })
+            .tcp_port = port;
+        EMISSARY_SUCCESS
+    })) {
+        Ok(result) => result,
+        Err(_) => EMISSARY_ERROR_GENERIC,
+    }
+}
+
+/// Pin the SAMv3 UDP port the router should bind (0 keeps OS assignment).
+#[no_mangle]
+pub extern "C" fn emissary_config_set_sam_udp_port(
+    config_ptr: *mut EmissaryConfig,
+    port: u16,
+) -> i32 {
+    if config_ptr.is_null() {
+        return EMISSARY_ERROR_INVALID_PARAM;
+    }
+
+    // Safety: We've checked for null pointer
+    let config = unsafe { &mut *config_ptr };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        config
+            .config
+            .samv3_config
+            .get_or_insert_with(|| SamConfig {
+                tcp_port: 0,
+                udp_port: 0,
+                host: "127.0.0.1".to_string(),
+            })
+            .udp_port = port;
+        EMISSARY_SUCCESS
+    })) {
+        Ok(result) => result,
+        Err(_) => EMISSARY_ERROR_GENERIC,
+    }
+}
+
 // ============================================================================
 // Error Code Constants (matching header file)
 // ============================================================================
@@ -120,6 +249,7 @@ impl EmissaryRouter {
 const EMISSARY_SUCCESS: i32 = 0;
 const EMISSARY_ERROR_GENERIC: i32 = -1;
 const EMISSARY_ERROR_INVALID_PARAM: i32 = -2;
+const EMISSARY_ERROR_TIMEOUT: i32 = -3;
 const EMISSARY_ERROR_ALREADY_STARTED: i32 = -4;
 const EMISSARY_ERROR_NOT_STARTED: i32 = -5;
 const EMISSARY_ERROR_NETWORK: i32 = -6;
@@ -148,6 +278,30 @@ pub extern "C" fn emissary_init() -> *mut EmissaryRouter {
     }
 }
 
+/// Initialize a new I2P router instance from a caller-supplied configuration.
+///
+/// The configuration is copied into the router, so the `emissary_config_t`
+/// handle remains owned by the caller and may be freed immediately. Returns
+/// NULL if `config_ptr` is null or allocation fails.
+#[no_mangle]
+pub extern "C" fn emissary_init_with_config(
+    config_ptr: *mut EmissaryConfig,
+) -> *mut EmissaryRouter {
+    if config_ptr.is_null() {
+        return ptr::null_mut();
+    }
+
+    // Safety: We've checked for null pointer; the handle is borrowed, not taken.
+    let config = unsafe { &*config_ptr };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        Box::into_raw(Box::new(EmissaryRouter::from_config(config.config.clone())))
+    })) {
+        Ok(router_ptr) => router_ptr,
+        Err(_) => ptr::null_mut(),
+    }
+}
+
 /// Start the I2P router and begin network operations
 #[no_mangle]
 pub extern "C" fn emissary_start(router_ptr: *mut EmissaryRouter) -> i32 {
@@ -178,109 +332,121 @@ pub extern "C" fn emissary_start(router_ptr: *mut EmissaryRouter) -> i32 {
         *state_guard = RouterState::Starting;
         drop(state_guard); // Release lock during async operations
 
-        // Create Tokio runtime for the router
-        let rt = match tokio::runtime::Builder::new_multi_thread()
-            .enable_all()
-            .build() 
-        {
-            Ok(rt) => Arc::new(rt),
-            Err(_) => {
-                // Reset state on failure
-                if let Ok(mut guard) = router.state.lock() {
-                    *guard = RouterState::Error;
-                }
-                return EMISSARY_ERROR_RESOURCE;
+        start_router(router)
+    })) {
+        Ok(result) => result,
+        Err(_) => {
+            // Panic occurred, reset state
+            if let Ok(mut guard) = router.state.lock() {
+                *guard = RouterState::Error;
+            }
+            EMISSARY_ERROR_GENERIC
+        }
+    }
+}
+
+/// Build and spawn the router from the current `Config`, transitioning the
+/// handle into `RouterState::Running` on success or `RouterState::Error` on
+/// failure. The caller must have already moved the handle into `Starting` (or
+/// `Stopping`, for a reload) and released the state lock. Returns an error code
+/// without touching the handle's memory, so callers retain ownership.
+fn start_router(router: &EmissaryRouter) -> i32 {
+    // Create Tokio runtime for the router
+    let rt = match tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(rt) => Arc::new(rt),
+        Err(_) => {
+            // Reset state on failure
+            if let Ok(mut guard) = router.state.lock() {
+                *guard = RouterState::Error;
             }
+            return EMISSARY_ERROR_RESOURCE;
+        }
+    };
+
+    // Start router asynchronously with improved error handling
+    let start_result = rt.block_on(async move {
+        // Read configuration
+        let config = match router.config.read() {
+            Ok(guard) => (*guard).clone(),
+            Err(_) => return Err(EMISSARY_ERROR_GENERIC),
         };
 
-        // Clone runtime for the blocking operation
-        let _rt_clone = Arc::clone(&rt);
-        
-        // Start router asynchronously with improved error handling
-        let start_result = rt.block_on(async move {
-            // Read configuration
-            let config = match router.config.read() {
-                Ok(guard) => (*guard).clone(),
-                Err(_) => return Err(EMISSARY_ERROR_GENERIC),
-            };
+        // Create router builder and build router
+        let builder = RouterBuilder::<Runtime>::new(config);
 
-            // Create router builder and build router
-            let builder = RouterBuilder::<Runtime>::new(config);
-            
-            match builder.build().await {
-                Ok((emissary_router, events, _router_info)) => {
-                    // Extract SAMv3 port information from the router
-                    let protocol_info = emissary_router.protocol_address_info();
-                    
-                    if let Some(sam_tcp) = protocol_info.sam_tcp {
-                        if let Ok(mut port_guard) = router.sam_tcp_port.write() {
-                            *port_guard = Some(sam_tcp.port());
-                        }
-                    }
-                    
-                    if let Some(sam_udp) = protocol_info.sam_udp {
-                        if let Ok(mut port_guard) = router.sam_udp_port.write() {
-                            *port_guard = Some(sam_udp.port());
-                        }
-                    }
+        match builder.build().await {
+            Ok((emissary_router, events, _router_info)) => {
+                // Extract SAMv3 port information from the router
+                let protocol_info = emissary_router.protocol_address_info();
 
-                    Ok((emissary_router, events))
+                if let Some(sam_tcp) = protocol_info.sam_tcp {
+                    if let Ok(mut port_guard) = router.sam_tcp_port.write() {
+                        *port_guard = Some(sam_tcp.port());
+                    }
                 }
-                Err(_e) => {
-                    // Log error details for debugging (in development builds)
-                    #[cfg(debug_assertions)]
-                    eprintln!("Router startup failed: {:?}", _e);
-                    
-                    Err(EMISSARY_ERROR_NETWORK)
-                },
-            }
-        });
 
-        match start_result {
-            Ok((emissary_router, events)) => {
-                // Create shutdown channel
-                let (shutdown_tx, mut shutdown_rx) = mpsc::channel(1);
-                
-                // Spawn router task in the runtime
-                let runtime_clone = Arc::clone(&rt);
-                runtime_clone.spawn(async move {
-                    tokio::select! {
-                        _ = emissary_router => {
-                            // Router completed
-                        }
-                        _ = shutdown_rx.recv() => {
-                            // Shutdown requested - router will be dropped
-                        }
+                if let Some(sam_udp) = protocol_info.sam_udp {
+                    if let Ok(mut port_guard) = router.sam_udp_port.write() {
+                        *port_guard = Some(sam_udp.port());
                     }
-                });
-
-                // Update state to running
-                if let Ok(mut guard) = router.state.lock() {
-                    *guard = RouterState::Running {
-                        _events: events,
-                        shutdown_tx,
-                        _runtime: runtime_clone,
-                    };
                 }
 
-                EMISSARY_SUCCESS
+                Ok((emissary_router, events))
             }
-            Err(error_code) => {
-                // Reset state on failure
-                if let Ok(mut guard) = router.state.lock() {
-                    *guard = RouterState::Error;
+            Err(e) => {
+                // Surface the failure to an embedding C host, and still print it
+                // to stderr in development builds.
+                let message = format!("router startup failed: {e:?}");
+                emit_log(EMISSARY_LOG_ERROR, "emissary::router", &message);
+                #[cfg(debug_assertions)]
+                eprintln!("Router startup failed: {e:?}");
+
+                Err(EMISSARY_ERROR_NETWORK)
+            }
+        }
+    });
+
+    match start_result {
+        Ok((emissary_router, events)) => {
+            // Create shutdown channel
+            let (shutdown_tx, mut shutdown_rx) = mpsc::channel(1);
+
+            // Spawn router task in the runtime
+            let runtime_clone = Arc::clone(&rt);
+            let join_handle = runtime_clone.spawn(async move {
+                tokio::select! {
+                    _ = emissary_router => {
+                        // Router completed
+                    }
+                    _ = shutdown_rx.recv() => {
+                        // Shutdown requested - router will be dropped
+                    }
                 }
-                error_code
+            });
+
+            // Update state to running
+            if let Ok(mut guard) = router.state.lock() {
+                *guard = RouterState::Running {
+                    _events: events,
+                    shutdown_tx,
+                    join_handle,
+                    started_at: std::time::Instant::now(),
+                    _runtime: runtime_clone,
+                };
             }
+
+            emit_log(EMISSARY_LOG_INFO, "emissary::router", "router started");
+            EMISSARY_SUCCESS
         }
-    })) {
-        Ok(result) => result,
-        Err(_) => {
-            // Panic occurred, reset state
+        Err(error_code) => {
+            // Reset state on failure
             if let Ok(mut guard) = router.state.lock() {
                 *guard = RouterState::Error;
             }
-            EMISSARY_ERROR_GENERIC
+            error_code
         }
     }
 }
@@ -342,6 +508,182 @@ pub extern "C" fn emissary_stop(router_ptr: *mut EmissaryRouter) -> i32 {
     }
 }
 
+/// Stop the I2P router, waiting up to `timeout_ms` for the router task to drain.
+///
+/// Unlike `emissary_stop`, which signals shutdown and returns immediately, this
+/// variant signals the router and then blocks, for at most `timeout_ms`, on the
+/// spawned task's join handle before the runtime is dropped. The task drops the
+/// router as soon as it receives the signal; in-flight streams and SAM sessions
+/// are not drained first. Returns `EMISSARY_ERROR_TIMEOUT` if the task did not
+/// finish within `timeout_ms` and had to be abandoned (forced drop); in that
+/// case the router is still left in the `Stopped` state.
+#[no_mangle]
+pub extern "C" fn emissary_stop_graceful(
+    router_ptr: *mut EmissaryRouter,
+    timeout_ms: u32,
+) -> i32 {
+    if router_ptr.is_null() {
+        return EMISSARY_ERROR_INVALID_PARAM;
+    }
+
+    // Safety: We've checked for null pointer
+    let router = unsafe { &*router_ptr };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let mut state_guard = match router.state.lock() {
+            Ok(guard) => guard,
+            Err(_) => return EMISSARY_ERROR_GENERIC,
+        };
+
+        match std::mem::replace(&mut *state_guard, RouterState::Stopping) {
+            RouterState::Running {
+                shutdown_tx,
+                join_handle,
+                _runtime,
+                ..
+            } => {
+                drop(state_guard); // Release lock during shutdown
+
+                // Signal shutdown, then await the task with a bounded timeout.
+                let _ = shutdown_tx.try_send(());
+
+                let deadline = std::time::Duration::from_millis(u64::from(timeout_ms));
+                let drained = _runtime.block_on(async move {
+                    tokio::time::timeout(deadline, join_handle).await.is_ok()
+                });
+
+                // Update state regardless of whether the drain completed: the
+                // runtime is about to be dropped either way.
+                if let Ok(mut guard) = router.state.lock() {
+                    *guard = RouterState::Stopped;
+                }
+
+                if drained {
+                    EMISSARY_SUCCESS
+                } else {
+                    EMISSARY_ERROR_TIMEOUT
+                }
+            }
+            other => {
+                // Restore the original state and report it was not running.
+                *state_guard = other;
+                match &*state_guard {
+                    RouterState::Error => EMISSARY_ERROR_GENERIC,
+                    _ => EMISSARY_ERROR_NOT_STARTED,
+                }
+            }
+        }
+    })) {
+        Ok(result) => result,
+        Err(_) => EMISSARY_ERROR_GENERIC,
+    }
+}
+
+/// Grace period, in milliseconds, given to the running task to drain during a
+/// `emissary_reload` before it is abandoned and the runtime is dropped.
+const EMISSARY_RELOAD_GRACE_MS: u64 = 5_000;
+
+/// Reconfigure-and-restart the router in place without freeing the handle.
+///
+/// Gracefully stops the running router task, then rebuilds from the current
+/// (possibly just-mutated) `Config` via `RouterBuilder` and resumes — all while
+/// the C caller keeps the same `emissary_router_t`. To keep downstream SAM
+/// clients connected across reloads, the previously assigned SAM TCP/UDP ports
+/// are pinned into the config before the rebuild instead of letting the OS pick
+/// new random ones — but only when the old task drained within the grace
+/// period. If the drain times out, the old listener may still hold its socket,
+/// so the previously assigned ports are not pinned and the config is left as
+/// it is: ports it leaves at 0 are assigned afresh by the OS, while ports pinned
+/// earlier (by the caller or a previous reload) are bound again. Returns
+/// `EMISSARY_ERROR_NOT_STARTED` if the router is neither running nor in the
+/// `Error` state. If the rebuild fails the handle is left in the `Error` state
+/// (not freed), and calling `emissary_reload` again retries the rebuild from
+/// the current config.
+#[no_mangle]
+pub extern "C" fn emissary_reload(router_ptr: *mut EmissaryRouter) -> i32 {
+    if router_ptr.is_null() {
+        return EMISSARY_ERROR_INVALID_PARAM;
+    }
+
+    // Safety: We've checked for null pointer
+    let router = unsafe { &*router_ptr };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let mut state_guard = match router.state.lock() {
+            Ok(guard) => guard,
+            Err(_) => return EMISSARY_ERROR_GENERIC,
+        };
+
+        let (shutdown_tx, join_handle, runtime) =
+            match std::mem::replace(&mut *state_guard, RouterState::Stopping) {
+                RouterState::Running {
+                    shutdown_tx,
+                    join_handle,
+                    _runtime,
+                    ..
+                } => (shutdown_tx, join_handle, _runtime),
+                RouterState::Error => {
+                    // A previous rebuild failed: retry it from the config as it
+                    // stands, which keeps any SAM ports already pinned.
+                    *state_guard = RouterState::Starting;
+                    drop(state_guard);
+                    return start_router(router);
+                }
+                other => {
+                    // Restore the original state and report it was not running.
+                    *state_guard = other;
+                    return EMISSARY_ERROR_NOT_STARTED;
+                }
+            };
+        drop(state_guard); // Release lock during shutdown/rebuild
+
+        // Gracefully drain the outgoing router task before the runtime drops,
+        // and only reuse the old SAM ports if the drain actually completed. A
+        // timed-out drain leaves the old listener's socket bound, so re-pinning
+        // the same ports would make `start_router` fail the rebind; in that case
+        // the config is left as it is, and only ports it leaves at 0 are
+        // assigned afresh by the OS.
+        let _ = shutdown_tx.try_send(());
+        let grace = std::time::Duration::from_millis(EMISSARY_RELOAD_GRACE_MS);
+        let drained = runtime.block_on(async move {
+            tokio::time::timeout(grace, join_handle).await.is_ok()
+        });
+        drop(runtime);
+
+        // Pin the previously assigned SAM ports into the config so downstream
+        // clients reconnect to the same endpoints after the rebuild — but only
+        // when the old listeners have actually released their sockets.
+        if drained {
+            if let Ok(mut config_guard) = router.config.write() {
+                if let Some(sam_config) = config_guard.samv3_config.as_mut() {
+                    if let Ok(tcp) = router.sam_tcp_port.read() {
+                        if let Some(port) = *tcp {
+                            sam_config.tcp_port = port;
+                        }
+                    }
+                    if let Ok(udp) = router.sam_udp_port.read() {
+                        if let Some(port) = *udp {
+                            sam_config.udp_port = port;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Rebuild and resume from the (possibly mutated) config. On failure
+        // `start_router` leaves the handle in the `Error` state.
+        start_router(router)
+    })) {
+        Ok(result) => result,
+        Err(_) => {
+            if let Ok(mut guard) = router.state.lock() {
+                *guard = RouterState::Error;
+            }
+            EMISSARY_ERROR_GENERIC
+        }
+    }
+}
+
 /// Destroy router instance and free all associated resources
 #[no_mangle]
 pub extern "C" fn emissary_destroy(router_ptr: *mut EmissaryRouter) {
@@ -359,6 +701,132 @@ pub extern "C" fn emissary_destroy(router_ptr: *mut EmissaryRouter) {
     });
 }
 
+// ============================================================================
+// Log Sink (forward router diagnostics to a C callback)
+// ============================================================================
+
+/// Stable C log levels, ordered from least to most verbose. `OFF` disables the
+/// sink entirely; the default is `INFO`.
+const EMISSARY_LOG_OFF: i32 = 0;
+const EMISSARY_LOG_ERROR: i32 = 1;
+const EMISSARY_LOG_WARN: i32 = 2;
+const EMISSARY_LOG_INFO: i32 = 3;
+const EMISSARY_LOG_DEBUG: i32 = 4;
+const EMISSARY_LOG_TRACE: i32 = 5;
+
+/// C callback invoked for each diagnostic record.
+///
+/// `target` and `message` are NUL-terminated UTF-8 strings valid only for the
+/// duration of the call; copy them out if they must outlive it. `user` is the
+/// opaque pointer supplied to `emissary_set_log_callback`.
+type EmissaryLogCallback = extern "C" fn(
+    level: i32,
+    target: *const c_char,
+    message: *const c_char,
+    user: *mut c_void,
+);
+
+/// Registered sink: the callback and its opaque user pointer.
+struct LogSink {
+    cb: EmissaryLogCallback,
+    user: *mut c_void,
+}
+
+// Safety: `user` is opaque to Rust and only passed back to the callback the
+// embedder registered; keeping it valid across threads is the embedder's
+// contract, documented on `emissary_set_log_callback`.
+unsafe impl Send for LogSink {}
+unsafe impl Sync for LogSink {}
+
+static LOG_SINK: Mutex<Option<LogSink>> = Mutex::new(None);
+static LOG_LEVEL: AtomicI32 = AtomicI32::new(EMISSARY_LOG_INFO);
+
+/// Forward a diagnostic record to the registered C log sink, if one is set and
+/// the configured level admits it.
+///
+/// Used by the FFI layer to surface the router diagnostics it emits (currently
+/// start success and startup failures). It does not yet bridge `emissary_core`'s
+/// internal `tracing` output, which would require a logging dependency this
+/// crate does not declare; the sink is wired so those records can be forwarded
+/// here once such a dependency lands.
+fn emit_log(level: i32, target: &str, message: &str) {
+    if level == EMISSARY_LOG_OFF || level > LOG_LEVEL.load(Ordering::Relaxed) {
+        return;
+    }
+
+    // Snapshot the callback and release the lock before calling out, so the
+    // handler never runs while we hold the sink mutex.
+    let (cb, user) = {
+        let guard = match LOG_SINK.lock() {
+            Ok(guard) => guard,
+            Err(_) => return,
+        };
+        match &*guard {
+            Some(sink) => (sink.cb, sink.user),
+            None => return,
+        }
+    };
+
+    let target = match CString::new(target) {
+        Ok(target) => target,
+        Err(_) => return,
+    };
+    let message = match CString::new(message) {
+        Ok(message) => message,
+        // Drop records whose rendered text contains an interior NUL.
+        Err(_) => return,
+    };
+
+    // Never let a panicking C handler unwind across the boundary.
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        cb(level, target.as_ptr(), message.as_ptr(), user);
+    }));
+}
+
+/// Install a callback that receives the router diagnostics the FFI layer emits.
+///
+/// The callback is invoked without holding any router or sink lock and is
+/// wrapped in `catch_unwind` so a panicking handler cannot unwind across the
+/// FFI boundary. Passing a null callback clears the sink. Safe to call at any
+/// time, including before `emissary_start`. `user` is passed back unchanged to
+/// every invocation, which may happen on any thread that calls into this API;
+/// the embedder keeps it valid until the sink is cleared or replaced.
+#[no_mangle]
+pub extern "C" fn emissary_set_log_callback(
+    cb: Option<EmissaryLogCallback>,
+    user: *mut c_void,
+) -> i32 {
+    match catch_unwind(AssertUnwindSafe(|| {
+        let mut guard = match LOG_SINK.lock() {
+            Ok(guard) => guard,
+            Err(_) => return EMISSARY_ERROR_GENERIC,
+        };
+
+        *guard = cb.map(|cb| LogSink { cb, user });
+        EMISSARY_SUCCESS
+    })) {
+        Ok(result) => result,
+        Err(_) => EMISSARY_ERROR_GENERIC,
+    }
+}
+
+/// Set the maximum level forwarded to the callback.
+///
+/// Accepts one of the `EMISSARY_LOG_*` levels; out-of-range values are treated
+/// as the most verbose (`TRACE`). The level is independent of the callback, so
+/// it may be set before or after `emissary_set_log_callback` without either
+/// clobbering the other.
+#[no_mangle]
+pub extern "C" fn emissary_set_log_level(level: i32) -> i32 {
+    let level = if (EMISSARY_LOG_OFF..=EMISSARY_LOG_TRACE).contains(&level) {
+        level
+    } else {
+        EMISSARY_LOG_TRACE
+    };
+    LOG_LEVEL.store(level, Ordering::Relaxed);
+    EMISSARY_SUCCESS
+}
+
 // ============================================================================
 // Status and Information Functions
 // ============================================================================
@@ -426,6 +894,81 @@ pub extern "C" fn emissary_sam_available(router_ptr: *mut EmissaryRouter) -> i32
     }
 }
 
+/// Table of addresses the router bound, one `*_port`/`*_available` pair per
+/// listener.
+///
+/// A `*_available` flag of `false` means the router did not bind that listener
+/// (the matching `*_port` is then 0 and must be ignored). Only the listeners
+/// `ProtocolAddressInfo` reports today — the SAMv3 TCP/UDP bridges — are
+/// surfaced; further listeners can be added here as the core exposes them.
+#[repr(C)]
+pub struct EmissaryProtocolInfo {
+    /// SAMv3 TCP control port.
+    pub sam_tcp_port: u16,
+    pub sam_tcp_available: bool,
+    /// SAMv3 UDP datagram port.
+    pub sam_udp_port: u16,
+    pub sam_udp_available: bool,
+}
+
+/// Copy an optional bound port into a `port`/`available` pair.
+fn fill_address(bound: Option<u16>, port: &mut u16, available: &mut bool) {
+    match bound {
+        Some(bound) => {
+            *port = bound;
+            *available = true;
+        }
+        None => {
+            *port = 0;
+            *available = false;
+        }
+    }
+}
+
+/// Fill `out` with the addresses the router bound on its most recent successful
+/// start, each guarded by a `*_available` flag. The recorded ports are kept
+/// after the router stops, so a stopped router still reports them.
+///
+/// This mirrors the `emissary_get_sam_tcp_port`/`_udp_port` getters in a single
+/// call and will grow additional listeners as `ProtocolAddressInfo` exposes
+/// them; until then it covers the same SAM ports, so the getters remain
+/// first-class rather than deprecated. Returns `EMISSARY_ERROR_NOT_STARTED` if
+/// the router has not bound its SAM ports yet (it has not completed a
+/// successful start).
+#[no_mangle]
+pub extern "C" fn emissary_get_protocol_info(
+    router_ptr: *mut EmissaryRouter,
+    out: *mut EmissaryProtocolInfo,
+) -> i32 {
+    if router_ptr.is_null() || out.is_null() {
+        return EMISSARY_ERROR_INVALID_PARAM;
+    }
+
+    // Safety: We've checked for null pointers
+    let router = unsafe { &*router_ptr };
+    let out = unsafe { &mut *out };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let (sam_tcp, sam_udp) = match (router.sam_tcp_port.read(), router.sam_udp_port.read()) {
+            (Ok(tcp), Ok(udp)) => (*tcp, *udp),
+            _ => return EMISSARY_ERROR_GENERIC,
+        };
+
+        // No ports recorded means the router has not completed a start.
+        if sam_tcp.is_none() && sam_udp.is_none() {
+            return EMISSARY_ERROR_NOT_STARTED;
+        }
+
+        fill_address(sam_tcp, &mut out.sam_tcp_port, &mut out.sam_tcp_available);
+        fill_address(sam_udp, &mut out.sam_udp_port, &mut out.sam_udp_available);
+
+        EMISSARY_SUCCESS
+    })) {
+        Ok(result) => result,
+        Err(_) => EMISSARY_ERROR_GENERIC,
+    }
+}
+
 /// Get SAMv3 TCP port number
 #[no_mangle]
 pub extern "C" fn emissary_get_sam_tcp_port(router_ptr: *mut EmissaryRouter) -> i32 {
@@ -473,3 +1016,59 @@ pub extern "C" fn emissary_get_sam_udp_port(router_ptr: *mut EmissaryRouter) ->
         Err(_) => EMISSARY_ERROR_GENERIC,
     }
 }
+
+// ============================================================================
+// Statistics / Metrics
+// ============================================================================
+
+/// Snapshot of router health counters.
+///
+/// `uptime_seconds` is tracked locally from the moment the router entered the
+/// `Running` state. Network counters (active tunnels, peer counts, byte totals)
+/// are intentionally absent: the running `emissary_core` router does not yet
+/// expose a metrics handle to read them from, and a field that always reads
+/// zero is indistinguishable from a genuine idle reading. They can be added
+/// here once a real counter source exists.
+#[repr(C)]
+pub struct EmissaryRouterStats {
+    /// Seconds elapsed since the router started.
+    pub uptime_seconds: u64,
+}
+
+/// Fill `out` with a snapshot of the running router's health counters.
+///
+/// Returns `EMISSARY_ERROR_NOT_STARTED` when the router is not in the `Running`
+/// state, so a C monitoring loop can poll safely without first checking status.
+#[no_mangle]
+pub extern "C" fn emissary_get_stats(
+    router_ptr: *mut EmissaryRouter,
+    out: *mut EmissaryRouterStats,
+) -> i32 {
+    if router_ptr.is_null() || out.is_null() {
+        return EMISSARY_ERROR_INVALID_PARAM;
+    }
+
+    // Safety: We've checked for null pointers
+    let router = unsafe { &*router_ptr };
+    let out = unsafe { &mut *out };
+
+    match catch_unwind(AssertUnwindSafe(|| {
+        let state_guard = match router.state.lock() {
+            Ok(guard) => guard,
+            Err(_) => return EMISSARY_ERROR_GENERIC,
+        };
+
+        let started_at = match &*state_guard {
+            RouterState::Running { started_at, .. } => *started_at,
+            _ => return EMISSARY_ERROR_NOT_STARTED,
+        };
+
+        // Uptime is the one counter we can source without a core metrics handle.
+        out.uptime_seconds = started_at.elapsed().as_secs();
+
+        EMISSARY_SUCCESS
+    })) {
+        Ok(result) => result,
+        Err(_) => EMISSARY_ERROR_GENERIC,
+    }
+}